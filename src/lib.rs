@@ -1,12 +1,20 @@
 #![doc = include_str!("../README.md")]
 
 
+mod reactive;
+pub use reactive::{ EqChangedPlugin, OnEqChanged };
+
+mod reflected;
+pub use reflected::EqChangedById;
+
 use core::marker::PhantomData;
+use std::collections::HashSet;
 #[cfg(not(feature = "intmap"))]
 use std::collections::{
     BTreeMap as HandledMap,
     btree_map::Entry as HandledMapEntry
 };
+use bevy_app::Update;
 use bevy_ecs::{
     archetype::Archetype,
     component::{
@@ -19,12 +27,16 @@ use bevy_ecs::{
     query::{
         QueryFilter,
         QueryData,
+        ReadOnlyQueryData,
         WorldQuery,
         FilteredAccess,
         Changed
     },
+    removal_detection::RemovedComponents,
     resource::Resource,
+    schedule::Schedules,
     storage::{ Table, TableRow },
+    system::Commands,
     world::{
         World,
         unsafe_world_cell::UnsafeWorldCell,
@@ -42,21 +54,111 @@ use intmap::{
 #[derive(Resource, Default)]
 struct NextQueryId(usize);
 
+/// Tracks the `(T, C)` pairs, by the [`ComponentId`] of their `PreviousValue<T, C>`, for which a
+///  [`cleanup_previous_value`] system has already been added to [`Update`], so that [`EqChangedBy`]'s [`init_state`](WorldQuery::init_state) does not add a
+///  duplicate system every time a new query is initialized for the same component.
+#[derive(Resource, Default)]
+struct CleanedUpComponents(HashSet<ComponentId>);
+
+/// Removes `PreviousValue<T, C>` from entities whose `T` was removed, so that a later re-add of `T`
+///  is not diffed against a stale value left over from before the removal, and so the shadow state
+///  does not accumulate on entities that no longer have `T`. Added to [`Update`] lazily, the first
+///  time `EqChangedBy<T, C>` is used for a given `(T, C)` pair, by [`register_cleanup`].
+fn cleanup_previous_value<T, C>(
+    mut commands : Commands,
+    mut removed  : RemovedComponents<T>
+)
+where
+    T : Component,
+    C : EqChangedComparator<T> + 'static
+{
+    for entity in removed.read() {
+        commands.entity(entity).remove::<PreviousValue<T, C>>();
+    }
+}
+
+/// Ensures a [`cleanup_previous_value::<T, C>`] system has been added to [`Update`] for this
+///  `(T, C)` pair, so that removing `T` from an entity also removes its shadow `PreviousValue<T, C>`.
+///  Both [`EqChangedBy`]'s `init_state` and [`EqChangedPlugin`](crate::EqChangedPlugin)'s `build`
+///  call this, since either one alone might be the only thing in an app using a given `T`.
+pub(crate) fn register_cleanup<T, C>(world : &mut World)
+where
+    T : Component,
+    C : EqChangedComparator<T> + 'static
+{
+    let previous_value_id = world.register_component::<PreviousValue<T, C>>();
+    let cleanup_not_yet_registered = world.get_resource_or_insert_with(CleanedUpComponents::default)
+        .0.insert(previous_value_id);
+    if (cleanup_not_yet_registered) {
+        // Unlike a component lifecycle hook, adding a system to a schedule has no constraint on
+        //  `T` already being present in an archetype, so this is safe to do lazily here instead
+        //  of requiring it be set up before `T` is ever used.
+        world.get_resource_or_insert_with(Schedules::default)
+            .add_systems(Update, cleanup_previous_value::<T, C>);
+    }
+}
+
 #[derive(Component)]
-struct PreviousValue<T> {
-    previous : T,
-    changed  : Tick,
-    handled  : HandledMap<usize, Tick>
+pub(crate) struct PreviousValue<T, C = DefaultComparator>
+where
+    C : EqChangedComparator<T>
+{
+    pub(crate) previous : C::Stored,
+    pub(crate) changed  : Tick,
+    pub(crate) handled  : HandledMap<usize, Tick>
+}
+
+
+/// A pluggable comparison strategy used by [`EqChangedBy<T, C>`] to decide whether a new value of
+///  `T` should be treated as changed, in place of hard-wiring the comparison to [`PartialEq`].
+///
+/// This allows things [`PartialEq`] alone can't express, like tolerance-based comparison for
+///  floating-point components (treat a `Transform` as unchanged if it moved less than an epsilon),
+///  or comparing a cheap digest instead of keeping a full clone of a large `T` around: the
+///  [`Stored`](EqChangedComparator::Stored) associated type is what actually gets kept in the
+///  shadow `PreviousValue<T, C>` component, so a comparator that digests `T` down to, say, a
+///  `u64` hash never has `T` itself cloned into storage.
+pub trait EqChangedComparator<T : ?Sized> {
+    /// The representation of `T` kept around for the next comparison. [`DefaultComparator`] uses a
+    ///  full clone of `T`; other comparators can use something cheaper, like a hash.
+    type Stored : PartialEq + Send + Sync + 'static;
+
+    /// Computes the representation of `value` that will be stored until the next comparison.
+    fn digest(value : &T) -> Self::Stored;
+
+    /// Returns `true` if `new` should be treated as the same value as the stored digest of the
+    ///  previous value.
+    fn is_unchanged(old : &Self::Stored, new : &T) -> bool;
+}
+
+/// The default [`EqChangedComparator`], backing [`EqChanged<T>`] by keeping a full clone of `T`
+///  and comparing with [`PartialEq::eq`].
+pub struct DefaultComparator;
+
+impl<T> EqChangedComparator<T> for DefaultComparator
+where
+    T : PartialEq + Clone + Send + Sync + 'static
+{
+    type Stored = T;
+
+    fn digest(value : &T) -> T {
+        value.clone()
+    }
+
+    fn is_unchanged(old : &T, new : &T) -> bool {
+        old == new
+    }
 }
 
 
 /// A filter on a component that only retains results the first time after they have been added or
-///  modified in a way where the new value [`!=`](PartialEq::ne) the previous.
+///  modified in a way where the new value is not [`unchanged`](EqChangedComparator::is_unchanged)
+///  according to `C`.
 ///
 /// A common use for this filter is avoiding redundant work when values have not changed.
 ///
 /// **Note** that unlike [`Changed`], *mutably dereferencing* a component
-///  is not enough to be considered a change. The new value must [`!=`](PartialEq::ne) the previous.
+///  is not enough to be considered a change. The new value must be considered changed by `C`.
 ///
 /// ### Deferred
 /// Note, that entity modifications issies with [`Commands`](bevy_ecs::system::Commands) (like entity
@@ -64,13 +166,21 @@ struct PreviousValue<T> {
 ///  applied, typically at the end of the schedule iteration.
 ///
 /// ### Time complexity
-/// `EqChanged` is not [`ArchetypeFilter`](bevy_ecs::query::ArchetypeFilter), which practically means
-///  that if query (with `T` component filter) matches million entities, `EqChanged<T>` filter will
+/// `EqChangedBy` is not [`ArchetypeFilter`](bevy_ecs::query::ArchetypeFilter), which practically means
+///  that if query (with `T` component filter) matches million entities, `EqChangedBy<T, C>` filter will
 ///  iterate over all of them even if none of them were changed.
 ///
-/// In order to track changes, `EqChanged` will [`Clone`] values when a modification has been detected.
-///  Usually, the value will only be cloned once per change, but some exceptions occur if an entity
-///  was spawned before the first time a system runs.
+/// In order to track changes, `EqChangedBy` will compute `C`'s [`digest`](EqChangedComparator::digest)
+///  of a value when a modification has been detected. Usually, the digest will only be computed once
+///  per change, but some exceptions occur if an entity was spawned before the first time a system
+///  runs.
+///
+/// ### Cleanup
+/// The shadow `PreviousValue<T, C>` component that backs this filter is removed automatically once
+///  `T` is removed from the entity, via a cleanup system added to [`Update`] the first time
+///  `EqChangedBy<T, C>` is used for a given `(T, C)` pair. This keeps a later re-add of `T` from
+///  being diffed against a stale value left over from before the removal, and keeps the shadow state
+///  from accumulating on entities that no longer have `T`.
 ///
 /// ### Examples
 /// ```rust
@@ -80,37 +190,44 @@ struct PreviousValue<T> {
 ///     }
 /// }
 /// ```
-pub struct EqChanged<T>(PhantomData<T>);
+pub struct EqChangedBy<T, C = DefaultComparator>(PhantomData<(T, C)>);
+
+/// [`EqChangedBy<T, C>`] using [`PartialEq`] (via [`DefaultComparator`]) to decide whether `T`
+///  changed. See `EqChangedBy` for the full documentation.
+pub type EqChanged<T> = EqChangedBy<T, DefaultComparator>;
 
 
 mod private {
     use super::*;
 
-    pub struct EqChangedState<T>
+    pub struct EqChangedState<T, C>
     where
-        T : Component
+        T : Component,
+        C : EqChangedComparator<T>
     {
         pub(crate) query_id : usize,
         pub(crate) changed  : <Changed<T> as WorldQuery>::State,
-        pub(crate) old      : <Option<&'static mut PreviousValue<T>> as WorldQuery>::State,
+        pub(crate) old      : <Option<&'static mut PreviousValue<T, C>> as WorldQuery>::State,
         pub(crate) new      : <&'static T as WorldQuery>::State
     }
 
-    pub struct EqChangedFetch<'l, T>
+    pub struct EqChangedFetch<'l, T, C>
     where
-        T : Component
+        T : Component,
+        C : EqChangedComparator<T>
     {
         pub(crate) world    : DeferredWorld<'l>,
         pub(crate) query_id : usize,
         pub(crate) this_run : Tick,
         pub(crate) changed  : <Changed<T> as WorldQuery>::Fetch<'l>,
-        pub(crate) old      : <Option<&'static mut PreviousValue<T>> as WorldQuery>::Fetch<'l>,
+        pub(crate) old      : <Option<&'static mut PreviousValue<T, C>> as WorldQuery>::Fetch<'l>,
         pub(crate) new      : <&'static T as WorldQuery>::Fetch<'l>
     }
 
-    impl<'l, T> Clone for EqChangedFetch<'l, T>
+    impl<'l, T, C> Clone for EqChangedFetch<'l, T, C>
     where
-        T : Component
+        T : Component,
+        C : EqChangedComparator<T>
     {
         fn clone(&self) -> Self { unreachable!() }
     }
@@ -119,9 +236,10 @@ mod private {
 use private::*;
 
 
-unsafe impl<T> QueryFilter for EqChanged<T>
+unsafe impl<T, C> QueryFilter for EqChangedBy<T, C>
 where
-    T : Component + PartialEq + Clone
+    T : Component,
+    C : EqChangedComparator<T>
 {
     const IS_ARCHETYPAL : bool = false;
 
@@ -133,20 +251,20 @@ where
         if (! <Changed<T> as QueryFilter>::filter_fetch(&mut fetch.changed, entity, table_row)) {
             return false;
         }
-        let old = <Option<&mut PreviousValue<T>> as QueryData>::fetch(&mut fetch.old, entity, table_row);
+        let old = <Option<&mut PreviousValue<T, C>> as QueryData>::fetch(&mut fetch.old, entity, table_row);
         let new = <&T as QueryData>::fetch(&mut fetch.new, entity, table_row);
         match (old) {
-            // PreviousValue<T> was not in the current query.
+            // PreviousValue<T, C> was not in the current query.
             None => {
                 let query_id = fetch.query_id;
                 let this_run = fetch.this_run;
-                let new      = new.clone();
+                let digest   = C::digest(new);
                 fetch.world.commands().entity(entity).queue(move |mut world : EntityWorldMut| {
-                    match (world.get_mut::<PreviousValue<T>>()) {
-                        // PreviousValue<T> does actually exist. It was just added in the same tick.
+                    match (world.get_mut::<PreviousValue<T, C>>()) {
+                        // PreviousValue<T, C> does actually exist. It was just added in the same tick.
                         Some(mut previous_value) => {
-                            if (new != previous_value.previous) {
-                                previous_value.previous = new;
+                            if (previous_value.previous != digest) {
+                                previous_value.previous = digest;
                                 previous_value.changed  = this_run;
                             }
                             let changed = previous_value.changed;
@@ -161,10 +279,10 @@ where
                                 }
                             }
                         },
-                        // PreviousValue<T> does not exist. Add it.
+                        // PreviousValue<T, C> does not exist. Add it.
                         None => {
                             let mut previous_value = PreviousValue {
-                                previous : new,
+                                previous : digest,
                                 changed  : this_run,
                                 handled  : HandledMap::new()
                             };
@@ -175,10 +293,10 @@ where
                 });
                 true
             },
-            // PreviousValue<T> was in the current query.
+            // PreviousValue<T, C> was in the current query.
             Some(mut previous_value) => {
-                if (new != &previous_value.previous) {
-                    previous_value.previous = new.clone();
+                if (! C::is_unchanged(&previous_value.previous, new)) {
+                    previous_value.previous = C::digest(new);
                     previous_value.changed  = fetch.this_run;
                 }
                 let changed = previous_value.changed;
@@ -200,12 +318,13 @@ where
 }
 
 
-unsafe impl<T> WorldQuery for EqChanged<T>
+unsafe impl<T, C> WorldQuery for EqChangedBy<T, C>
 where
-    T : Component
+    T : Component,
+    C : EqChangedComparator<T> + 'static
 {
-    type Fetch<'a> = EqChangedFetch<'a, T>;
-    type State     = EqChangedState<T>;
+    type Fetch<'a> = EqChangedFetch<'a, T, C>;
+    type State     = EqChangedState<T, C>;
 
     fn shrink_fetch<'wlong : 'wshort, 'wshort>(
         fetch : Self::Fetch<'wlong>
@@ -215,7 +334,7 @@ where
             query_id : fetch.query_id,
             this_run : fetch.this_run,
             changed  : <Changed<T> as WorldQuery>::shrink_fetch(fetch.changed),
-            old      : <Option<&mut PreviousValue<T>> as WorldQuery>::shrink_fetch(fetch.old),
+            old      : <Option<&mut PreviousValue<T, C>> as WorldQuery>::shrink_fetch(fetch.old),
             new      : <&T as WorldQuery>::shrink_fetch(fetch.new)
         }
     }
@@ -231,14 +350,14 @@ where
             query_id : state.query_id,
             this_run,
             changed  : <Changed<T> as WorldQuery>::init_fetch(world, &state.changed, last_run, this_run),
-            old      : <Option<&mut PreviousValue<T>> as WorldQuery>::init_fetch(world, &state.old, last_run, this_run),
+            old      : <Option<&mut PreviousValue<T, C>> as WorldQuery>::init_fetch(world, &state.old, last_run, this_run),
             new      : <&T as WorldQuery>::init_fetch(world, &state.new, last_run, this_run)
         } }
     }
 
     const IS_DENSE : bool =
         <Changed<T> as WorldQuery>::IS_DENSE
-        && <&mut PreviousValue<T> as WorldQuery>::IS_DENSE
+        && <&mut PreviousValue<T, C> as WorldQuery>::IS_DENSE
         && <&T as WorldQuery>::IS_DENSE;
 
     unsafe fn set_archetype<'w>(
@@ -248,7 +367,7 @@ where
         table     : &'w Table
     ) { unsafe {
         <Changed<T> as WorldQuery>::set_archetype(&mut fetch.changed, &state.changed, archetype, table);
-        <Option<&mut PreviousValue<T>> as WorldQuery>::set_archetype(&mut fetch.old, &state.old, archetype, table);
+        <Option<&mut PreviousValue<T, C>> as WorldQuery>::set_archetype(&mut fetch.old, &state.old, archetype, table);
         <&T as WorldQuery>::set_archetype(&mut fetch.new, &state.new, archetype, table);
     } }
 
@@ -258,7 +377,7 @@ where
         table : &'w Table
     ) { unsafe {
         <Changed<T> as WorldQuery>::set_table(&mut fetch.changed, &state.changed, table);
-        <Option<&mut PreviousValue<T>> as WorldQuery>::set_table(&mut fetch.old, &state.old, table);
+        <Option<&mut PreviousValue<T, C>> as WorldQuery>::set_table(&mut fetch.old, &state.old, table);
         <&T as WorldQuery>::set_table(&mut fetch.new, &state.new, table);
     } }
 
@@ -267,13 +386,14 @@ where
         access : &mut FilteredAccess<ComponentId>
     ) {
         <Changed<T> as WorldQuery>::update_component_access(&state.changed, access);
-        <Option<&mut PreviousValue<T>> as WorldQuery>::update_component_access(&state.old, access);
+        <Option<&mut PreviousValue<T, C>> as WorldQuery>::update_component_access(&state.old, access);
         <&T as WorldQuery>::update_component_access(&state.new, access);
     }
 
     fn init_state(
         world : &mut World
     ) -> Self::State {
+        register_cleanup::<T, C>(world);
         EqChangedState {
             query_id : {
                 let mut next_query_id = world.get_resource_or_insert_with(NextQueryId::default);
@@ -282,7 +402,7 @@ where
                 query_id
             },
             changed  : <Changed<T> as WorldQuery>::init_state(world),
-            old      : <Option<&mut PreviousValue<T>> as WorldQuery>::init_state(world),
+            old      : <Option<&mut PreviousValue<T, C>> as WorldQuery>::init_state(world),
             new      : <&T as WorldQuery>::init_state(world)
         }
     }
@@ -296,7 +416,250 @@ where
         set_contains_id : &impl Fn(ComponentId) -> bool,
     ) -> bool {
         <Changed<T> as WorldQuery>::matches_component_set(&state.changed, set_contains_id)
-        && <Option<&mut PreviousValue<T>> as WorldQuery>::matches_component_set(&state.new, set_contains_id)
+        && <Option<&mut PreviousValue<T, C>> as WorldQuery>::matches_component_set(&state.new, set_contains_id)
         && <&T as WorldQuery>::matches_component_set(&state.new, set_contains_id)
     }
 }
+
+
+/// Query data that, like [`Ref<T>`](bevy_ecs::change_detection::Ref), exposes both the value and
+///  whether it changed, but mirrors [`EqChanged`]'s value-equality semantics instead of [`Changed`]'s
+///  mutable-dereference semantics. Reads the *current* `T`, the *previous* `T` (if one has been
+///  observed yet), and whether the current value [`!=`](PartialEq::ne) the previous one.
+///
+/// Unlike [`EqChanged`], `EqRef` is a [`QueryData`] rather than a [`QueryFilter`], so it does not
+///  drop non-matching entities from the query; every entity matched by the rest of the query is
+///  returned, with [`is_eq_changed`](EqRefItem::is_eq_changed) telling you whether this particular
+///  fetch observed a real change.
+///
+/// ### Examples
+/// ```rust
+/// fn print_moved_distance_system(query: Query<(&Name, EqRef<Transform>)>) {
+///     for (name, transform) in &query {
+///         if let Some(previous) = transform.previous() {
+///             if (transform.is_eq_changed()) {
+///                 let distance = transform.translation.distance(previous.translation);
+///                 println!("{:?} moved {distance}", name);
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct EqRef<T>(PhantomData<T>);
+
+
+/// The [`QueryData::Item`] yielded by [`EqRef<T>`].
+///
+/// Derefs to the current `T` for convenience.
+pub struct EqRefItem<'w, T> {
+    current  : &'w T,
+    previous : Option<&'w T>,
+    changed  : bool
+}
+
+impl<'w, T> EqRefItem<'w, T> {
+    /// The current value of `T`.
+    pub fn current(&self) -> &'w T { self.current }
+
+    /// The previous value of `T`, or [`None`] if this is the first time the entity has been seen.
+    pub fn previous(&self) -> Option<&'w T> { self.previous }
+
+    /// Whether the current value [`!=`](PartialEq::ne) the previous one. Always `true` the first
+    ///  time an entity is observed.
+    pub fn is_eq_changed(&self) -> bool { self.changed }
+}
+
+impl<'w, T> core::ops::Deref for EqRefItem<'w, T> {
+    type Target = T;
+    fn deref(&self) -> &T { self.current }
+}
+
+
+mod private_ref {
+    use super::*;
+
+    pub struct EqRefState<T>
+    where
+        T : Component
+    {
+        pub(crate) old : <Option<&'static PreviousValue<T>> as WorldQuery>::State,
+        pub(crate) new : <&'static T as WorldQuery>::State
+    }
+
+    pub struct EqRefFetch<'l, T>
+    where
+        T : Component
+    {
+        pub(crate) world    : DeferredWorld<'l>,
+        pub(crate) this_run : Tick,
+        pub(crate) old      : <Option<&'static PreviousValue<T>> as WorldQuery>::Fetch<'l>,
+        pub(crate) new      : <&'static T as WorldQuery>::Fetch<'l>
+    }
+
+    impl<'l, T> Clone for EqRefFetch<'l, T>
+    where
+        T : Component
+    {
+        fn clone(&self) -> Self { unreachable!() }
+    }
+
+}
+use private_ref::*;
+
+
+unsafe impl<T> WorldQuery for EqRef<T>
+where
+    T : Component + PartialEq + Clone
+{
+    type Fetch<'a> = EqRefFetch<'a, T>;
+    type State     = EqRefState<T>;
+
+    fn shrink_fetch<'wlong : 'wshort, 'wshort>(
+        fetch : Self::Fetch<'wlong>
+    ) -> Self::Fetch<'wshort> {
+        EqRefFetch {
+            world    : fetch.world,
+            this_run : fetch.this_run,
+            old      : <Option<&PreviousValue<T>> as WorldQuery>::shrink_fetch(fetch.old),
+            new      : <&T as WorldQuery>::shrink_fetch(fetch.new)
+        }
+    }
+
+    unsafe fn init_fetch<'w>(
+        world    : UnsafeWorldCell<'w>,
+        state    : &Self::State,
+        last_run : Tick,
+        this_run : Tick,
+    ) -> Self::Fetch<'w> { unsafe {
+        EqRefFetch {
+            world    : world.into_deferred(),
+            this_run,
+            old : <Option<&PreviousValue<T>> as WorldQuery>::init_fetch(world, &state.old, last_run, this_run),
+            new : <&T as WorldQuery>::init_fetch(world, &state.new, last_run, this_run)
+        } }
+    }
+
+    const IS_DENSE : bool =
+        <&PreviousValue<T> as WorldQuery>::IS_DENSE
+        && <&T as WorldQuery>::IS_DENSE;
+
+    unsafe fn set_archetype<'w>(
+        fetch     : &mut Self::Fetch<'w>,
+        state     : &Self::State,
+        archetype : &'w Archetype,
+        table     : &'w Table
+    ) { unsafe {
+        <Option<&PreviousValue<T>> as WorldQuery>::set_archetype(&mut fetch.old, &state.old, archetype, table);
+        <&T as WorldQuery>::set_archetype(&mut fetch.new, &state.new, archetype, table);
+    } }
+
+    unsafe fn set_table<'w>(
+        fetch : &mut Self::Fetch<'w>,
+        state : &Self::State,
+        table : &'w Table
+    ) { unsafe {
+        <Option<&PreviousValue<T>> as WorldQuery>::set_table(&mut fetch.old, &state.old, table);
+        <&T as WorldQuery>::set_table(&mut fetch.new, &state.new, table);
+    } }
+
+    fn update_component_access(
+        state  : &Self::State,
+        access : &mut FilteredAccess<ComponentId>
+    ) {
+        <Option<&PreviousValue<T>> as WorldQuery>::update_component_access(&state.old, access);
+        <&T as WorldQuery>::update_component_access(&state.new, access);
+    }
+
+    fn init_state(
+        world : &mut World
+    ) -> Self::State {
+        EqRefState {
+            old : <Option<&PreviousValue<T>> as WorldQuery>::init_state(world),
+            new : <&T as WorldQuery>::init_state(world)
+        }
+    }
+
+    fn get_state(_components : &Components) -> Option<Self::State> {
+        None
+    }
+
+    fn matches_component_set(
+        state           : &Self::State,
+        set_contains_id : &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <Option<&PreviousValue<T>> as WorldQuery>::matches_component_set(&state.new, set_contains_id)
+        && <&T as WorldQuery>::matches_component_set(&state.new, set_contains_id)
+    }
+}
+
+
+unsafe impl<T> QueryData for EqRef<T>
+where
+    T : Component + PartialEq + Clone
+{
+    type ReadOnly = Self;
+    type Item<'w> = EqRefItem<'w, T>;
+
+    fn shrink<'wlong : 'wshort, 'wshort>(
+        item : Self::Item<'wlong>
+    ) -> Self::Item<'wshort> {
+        EqRefItem {
+            current  : item.current,
+            previous : item.previous,
+            changed  : item.changed
+        }
+    }
+
+    unsafe fn fetch<'w>(
+        fetch     : &mut Self::Fetch<'w>,
+        entity    : Entity,
+        table_row : TableRow,
+    ) -> Self::Item<'w> { unsafe {
+        let old = <Option<&PreviousValue<T>> as QueryData>::fetch(&mut fetch.old, entity, table_row);
+        let new = <&T as QueryData>::fetch(&mut fetch.new, entity, table_row);
+        match (old) {
+            // PreviousValue<T> was not in the current query. Queue it up to be inserted, same as
+            //  EqChanged does, so that the next fetch has a previous value to compare against.
+            None => {
+                let this_run = fetch.this_run;
+                let inserted  = new.clone();
+                fetch.world.commands().entity(entity).queue(move |mut world : EntityWorldMut| {
+                    if (world.get::<PreviousValue<T>>().is_none()) {
+                        world.insert(PreviousValue {
+                            previous : inserted,
+                            changed  : this_run,
+                            handled  : HandledMap::new()
+                        });
+                    }
+                });
+                EqRefItem { current : new, previous : None, changed : true }
+            },
+            // PreviousValue<T> was in the current query.
+            Some(previous_value) => {
+                let changed = new != &previous_value.previous;
+                // Borrow the genuinely-old value for the item *before* queuing its update, so that
+                //  `EqRefItem::previous()` never observes the value this same fetch just wrote.
+                let previous : &'w T = &previous_value.previous;
+                if (changed) {
+                    let this_run = fetch.this_run;
+                    let new       = new.clone();
+                    fetch.world.commands().entity(entity).queue(move |mut world : EntityWorldMut| {
+                        if let Some(mut previous_value) = world.get_mut::<PreviousValue<T>>() {
+                            previous_value.previous = new;
+                            previous_value.changed  = this_run;
+                        }
+                    });
+                }
+                EqRefItem { current : new, previous : Some(previous), changed }
+            }
+        }
+    } }
+}
+
+// SAFETY: `EqRefItem` only ever hands out shared references to `T`. `fetch` only ever reads
+//  `PreviousValue<T>` in place (`old` is now a shared `&PreviousValue<T>` fetch); any actual update
+//  to the shadow value is deferred through `Commands`, never performed through the item itself.
+unsafe impl<T> ReadOnlyQueryData for EqRef<T>
+where
+    T : Component + PartialEq + Clone
+{}
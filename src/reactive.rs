@@ -0,0 +1,96 @@
+use core::marker::PhantomData;
+#[cfg(not(feature = "intmap"))]
+use std::collections::BTreeMap as HandledMap;
+#[cfg(feature = "intmap")]
+use intmap::IntMap as HandledMap;
+use bevy_app::{ App, Plugin, Update };
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    query::Changed,
+    system::{ Commands, Query, SystemChangeTick }
+};
+
+use crate::{ register_cleanup, DefaultComparator, PreviousValue };
+
+
+/// A [`Plugin`] that, for a single `T`, drives the reactive counterpart of [`EqChanged<T>`](crate::EqChanged):
+///  whenever `T` changes to a value that [`!=`](PartialEq::ne) the one last observed, a
+///  [`Trigger<OnEqChanged<T>>`](bevy_ecs::observer::Trigger) is emitted for the entity instead of
+///  leaving callers to poll a query every frame.
+///
+/// This is useful when the number of entities carrying `T` is large but real changes are rare; an
+///  observer only runs for the entities that actually changed, instead of `EqChanged<T>` having to
+///  iterate every matching entity to find out.
+///
+/// ```rust
+/// app.add_plugins(EqChangedPlugin::<Transform>::default());
+/// app.add_observer(|trigger: Trigger<OnEqChanged<Transform>>| {
+///     println!("{:?} moved from {:?} to {:?}", trigger.entity(), trigger.old, trigger.new);
+/// });
+/// ```
+pub struct EqChangedPlugin<T>(PhantomData<T>);
+
+impl<T> Default for EqChangedPlugin<T> {
+    fn default() -> Self { Self(PhantomData) }
+}
+
+impl<T> Plugin for EqChangedPlugin<T>
+where
+    T : Component + PartialEq + Clone
+{
+    fn build(&self, app : &mut App) {
+        // Shared with EqChangedBy<T, _>, since this plugin's PreviousValue<T> (= PreviousValue<T,
+        //  DefaultComparator>) is the same shadow component, and either one might be the only thing
+        //  in the app using T.
+        register_cleanup::<T, DefaultComparator>(app.world_mut());
+        app.add_systems(Update, detect_eq_changed::<T>);
+    }
+}
+
+
+/// The event triggered by [`EqChangedPlugin<T>`] for an entity whose `T` changed to a value that
+///  [`!=`](PartialEq::ne) the one last observed.
+#[derive(Event)]
+pub struct OnEqChanged<T> {
+    /// The entity whose `T` changed.
+    pub entity : Entity,
+    /// The value of `T` before the change.
+    pub old    : T,
+    /// The value of `T` after the change.
+    pub new    : T
+}
+
+
+fn detect_eq_changed<T>(
+    mut commands : Commands,
+    ticks        : SystemChangeTick,
+    mut query    : Query<(Entity, &T, Option<&mut PreviousValue<T>>), Changed<T>>
+)
+where
+    T : Component + PartialEq + Clone
+{
+    for (entity, new, old) in &mut query {
+        match (old) {
+            // PreviousValue<T> was not on this entity yet. Record the current value without
+            //  triggering, since there is no old value to report yet (unlike EqChanged, which
+            //  reports the first observation as a change).
+            None => {
+                commands.entity(entity).insert(PreviousValue {
+                    previous : new.clone(),
+                    changed  : ticks.this_run(),
+                    handled  : HandledMap::new()
+                });
+            },
+            // PreviousValue<T> already exists, compare against it.
+            Some(mut previous_value) => {
+                if (new != &previous_value.previous) {
+                    let old = core::mem::replace(&mut previous_value.previous, new.clone());
+                    previous_value.changed = ticks.this_run();
+                    commands.trigger_targets(OnEqChanged { entity, old, new : new.clone() }, entity);
+                }
+            }
+        }
+    }
+}
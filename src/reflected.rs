@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+#[cfg(not(feature = "intmap"))]
+use std::collections::{
+    BTreeMap as HandledMap,
+    btree_map::Entry as HandledMapEntry
+};
+#[cfg(feature = "intmap")]
+use intmap::{
+    IntMap as HandledMap,
+    Entry as HandledMapEntry
+};
+use bevy_ecs::{
+    component::{ Component, ComponentId },
+    query::QueryBuilder,
+    reflect::AppTypeRegistry,
+    world::{ FilteredEntityMut, World }
+};
+use bevy_reflect::{ PartialReflect, ReflectFromPtr };
+
+use crate::NextQueryId;
+
+
+/// The per-component shadow state tracked by [`EqChangedById`]. `changed` is bumped every time
+///  `previous` is actually replaced, and `handled` records, per caller, the last `changed` value
+///  that caller has already observed — the same `handled: HandledMap<usize, _>` bookkeeping the
+///  typed `EqChangedBy<T, _>` filter uses so that multiple independent callers each observe a real
+///  change exactly once.
+struct DynEntry {
+    previous : Box<dyn PartialReflect>,
+    changed  : u64,
+    handled  : HandledMap<usize, u64>
+}
+
+#[derive(Component, Default)]
+struct PreviousValueDyn {
+    values : HashMap<ComponentId, DynEntry>
+}
+
+
+/// The dynamic-ECS counterpart of [`EqChanged<T>`](crate::EqChanged), for components only known at
+///  runtime by their [`ComponentId`] (for example ones created dynamically for a scripting or editor
+///  integration) rather than at compile time by a concrete `T`.
+///
+/// Because a runtime [`ComponentId`] cannot be threaded through the static [`QueryData`]/
+///  [`QueryFilter`](bevy_ecs::query::QueryFilter) traits (every instance of a given filter type must
+///  behave identically, but two `EqChangedById`s name different components), this is used together
+///  with [`QueryBuilder`] and [`FilteredEntityMut`] rather than as a drop-in `Query<D, F>` filter:
+///
+/// ```rust
+/// let eq_changed = EqChangedById::new(&mut world, component_id);
+/// let type_registry = world.resource::<AppTypeRegistry>().clone();
+/// let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world);
+/// eq_changed.register(&mut query);
+/// let mut query = query.build();
+/// for mut entity in query.iter_mut(&mut world) {
+///     if (eq_changed.check(&type_registry, &mut entity)) {
+///         // `entity`'s dynamic component changed by value since it was last observed.
+///     }
+/// }
+/// ```
+///
+/// Value equality is checked with [`bevy_reflect`] via [`PartialReflect::reflect_partial_eq`]
+///  instead of [`PartialEq`]. An entity whose component is not registered in the
+///  [`AppTypeRegistry`], or whose type does not support [`ReflectFromPtr`], is treated as unchanged
+///  (`check` returns `false`) rather than panicking, since neither can be interpreted from its raw
+///  pointer.
+///
+/// Unlike `EqChanged<T>`, there is no cheap [`Changed<T>`](bevy_ecs::query::Changed) shortcut
+///  available for a component only known by id, so every matched entity has its current value
+///  compared against the stored one on every call to `check`.
+///
+/// Like the typed filters, each `EqChangedById` tracks its own "have I already observed this
+///  particular change" state, keyed by an id allocated from [`World`] when it's constructed. Two
+///  independent `EqChangedById`s checking the same `component_id` on the same entity in the same
+///  tick each see a real change exactly once, instead of the second caller stealing the first one's
+///  signal.
+pub struct EqChangedById {
+    component_id : ComponentId,
+    caller_id    : usize
+}
+
+impl EqChangedById {
+    /// Construct a filter that performs value-equality change detection on the given component,
+    ///  identified dynamically by its [`ComponentId`]. Allocates this caller its own tracking slot,
+    ///  the same way every `EqChangedBy<T, _>` query gets its own `query_id`.
+    pub fn new(world : &mut World, component_id : ComponentId) -> Self {
+        let caller_id = {
+            let mut next_query_id = world.get_resource_or_insert_with(NextQueryId::default);
+            let caller_id = next_query_id.0;
+            next_query_id.0 += 1;
+            caller_id
+        };
+        Self { component_id, caller_id }
+    }
+
+    /// The [`ComponentId`] this filter was constructed for.
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+
+    /// Registers the access this filter needs (read access to the dynamic component, and read-write
+    ///  access to the crate's internal shadow-value storage) on a [`QueryBuilder`].
+    pub fn register(&self, builder : &mut QueryBuilder<FilteredEntityMut>) {
+        builder.ref_id(self.component_id);
+        builder.data::<&mut PreviousValueDyn>();
+    }
+
+    /// Returns whether `entity`'s dynamic component changed by value since this caller last
+    ///  observed it, recording the new value if so. Entities whose component isn't Reflect-registered
+    ///  are treated as unchanged.
+    pub fn check(
+        &self,
+        type_registry : &AppTypeRegistry,
+        entity        : &mut FilteredEntityMut
+    ) -> bool {
+        let component_id = self.component_id;
+        let caller_id    = self.caller_id;
+
+        // The entity no longer has the component (dynamic queries don't guarantee presence the
+        //  way `&T` does), or the component isn't Reflect-registered. Nothing to compare against.
+        let Some(type_id) = entity.world().components().get_info(component_id).and_then(|info| info.type_id()) else {
+            return false;
+        };
+        let registry = type_registry.read();
+        let Some(reflect_from_ptr) = registry.get(type_id).and_then(|registration| registration.data::<ReflectFromPtr>()) else {
+            return false;
+        };
+        let Some(ptr) = entity.get_by_id(component_id) else {
+            return false;
+        };
+        // SAFETY: `ptr` was obtained from `component_id`, which is the same component
+        //  `reflect_from_ptr` was registered for.
+        let current : &dyn PartialReflect = unsafe { reflect_from_ptr.as_reflect(ptr) };
+
+        let mut previous_values = entity.get_mut::<PreviousValueDyn>();
+        match (&mut previous_values) {
+            Some(previous_values) => {
+                match (previous_values.values.get_mut(&component_id)) {
+                    Some(dyn_entry) => {
+                        if (dyn_entry.previous.reflect_partial_eq(current) != Some(true)) {
+                            if let Some(cloned) = current.reflect_clone().ok().map(|cloned| cloned.into_partial_reflect()) {
+                                dyn_entry.previous = cloned;
+                                dyn_entry.changed += 1;
+                            }
+                        }
+                        let changed = dyn_entry.changed;
+                        match (dyn_entry.handled.entry(caller_id)) {
+                            HandledMapEntry::Occupied(mut handled_entry) => {
+                                if (handled_entry.get() != &changed) {
+                                    handled_entry.insert(changed);
+                                    true
+                                } else { false }
+                            },
+                            HandledMapEntry::Vacant(handled_entry) => {
+                                handled_entry.insert(changed);
+                                true
+                            }
+                        }
+                    },
+                    None => {
+                        let Some(cloned) = current.reflect_clone().ok().map(|cloned| cloned.into_partial_reflect()) else {
+                            return false;
+                        };
+                        let mut dyn_entry = DynEntry { previous : cloned, changed : 0, handled : HandledMap::new() };
+                        dyn_entry.handled.insert(caller_id, 0);
+                        previous_values.values.insert(component_id, dyn_entry);
+                        true
+                    }
+                }
+            },
+            None => {
+                let Some(cloned) = current.reflect_clone().ok().map(|cloned| cloned.into_partial_reflect()) else {
+                    return false;
+                };
+                let mut dyn_entry = DynEntry { previous : cloned, changed : 0, handled : HandledMap::new() };
+                dyn_entry.handled.insert(caller_id, 0);
+                let mut values = HashMap::new();
+                values.insert(component_id, dyn_entry);
+                // SAFETY: `FilteredEntityMut` was built with write access to `PreviousValueDyn` via
+                //  `Self::register`.
+                unsafe { entity.insert(PreviousValueDyn { values }) };
+                true
+            }
+        }
+    }
+}